@@ -0,0 +1,53 @@
+use std::fs;
+
+use tauri::http::{Request, Response, ResponseBuilder};
+use tauri::{AppHandle, Manager};
+use url::Url;
+
+use crate::notebook_file::NotebookState;
+use crate::workspace;
+
+/// Handles `notebook://` requests, resolving the requested path relative to
+/// the currently open notebook's directory and streaming the file back with
+/// its guessed MIME type. Lets the webview load sidecar images/attachments
+/// without base64-inlining them into notebook JSON.
+///
+/// Because `scheme://...` URLs always parse the first `/`-delimited segment
+/// as the authority (host), callers must include a placeholder host, e.g.
+/// `notebook://_/relative/path.png` to reach `<notebook_dir>/relative/path.png` —
+/// everything after that placeholder is treated as the path, so a real
+/// subdirectory can never be mistaken for the host and silently dropped.
+pub fn handle_notebook_asset(
+    app: &AppHandle,
+    request: &Request,
+) -> Result<Response, Box<dyn std::error::Error>> {
+    let state = app.state::<NotebookState>();
+    let notebook_dir = state
+        .0
+        .lock()
+        .unwrap()
+        .clone()
+        .ok_or("No notebook is currently open")?;
+
+    let url = Url::parse(request.uri())?;
+    let decoded = percent_encoding::percent_decode_str(url.path().trim_start_matches('/'))
+        .decode_utf8_lossy()
+        .to_string();
+
+    let boundary = workspace::active_workspace_root().unwrap_or_else(|| notebook_dir.clone());
+    let resolved = notebook_dir.join(&decoded);
+
+    let canonical_boundary = boundary.canonicalize()?;
+    let canonical_target = resolved.canonicalize()?;
+
+    if !canonical_target.starts_with(&canonical_boundary) {
+        return Err("Requested asset escapes the workspace root".into());
+    }
+
+    let bytes = fs::read(&canonical_target)?;
+    let mime = mime_guess::from_path(&canonical_target)
+        .first_or_octet_stream()
+        .to_string();
+
+    ResponseBuilder::new().mimetype(&mime).body(bytes).map_err(Into::into)
+}
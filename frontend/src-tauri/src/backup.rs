@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::app_data_dir;
+
+/// How many autosave snapshots to keep per notebook, mirroring the recent
+/// files list's truncation to a fixed count.
+const MAX_BACKUPS_PER_NOTEBOOK: usize = 10;
+
+#[derive(serde::Serialize)]
+pub struct BackupEntry {
+    id: String,
+    path: String,
+    timestamp: u64,
+}
+
+fn backups_root() -> Result<PathBuf, String> {
+    Ok(app_data_dir()?.join("backups"))
+}
+
+fn notebook_hash(path: &str) -> String {
+    blake3::hash(path.as_bytes()).to_hex().to_string()
+}
+
+fn backup_dir_for(path: &str) -> Result<PathBuf, String> {
+    Ok(backups_root()?.join(notebook_hash(path)))
+}
+
+fn backup_id(notebook_hash: &str, timestamp: u64) -> String {
+    format!("{}/{}", notebook_hash, timestamp)
+}
+
+#[tauri::command]
+pub async fn autosave_notebook(path: String, content: String) -> Result<(), String> {
+    let dir = backup_dir_for(&path)?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backup directory: {}", e))?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Invalid system time: {}", e))?
+        .as_secs();
+
+    fs::write(dir.join(format!("{}.json", timestamp)), content)
+        .map_err(|e| format!("Failed to write backup: {}", e))?;
+
+    prune_old_backups(&dir)
+}
+
+fn prune_old_backups(dir: &Path) -> Result<(), String> {
+    let mut snapshots: Vec<(u64, PathBuf)> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to list backups: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let timestamp: u64 = path.file_stem()?.to_str()?.parse().ok()?;
+            Some((timestamp, path))
+        })
+        .collect();
+
+    snapshots.sort_by(|a, b| b.0.cmp(&a.0));
+
+    for (_, path) in snapshots.into_iter().skip(MAX_BACKUPS_PER_NOTEBOOK) {
+        let _ = fs::remove_file(path);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_backups(path: String) -> Result<Vec<BackupEntry>, String> {
+    let dir = backup_dir_for(&path)?;
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let hash = notebook_hash(&path);
+    let mut entries: Vec<BackupEntry> = fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to list backups: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_path = entry.path();
+            let timestamp: u64 = file_path.file_stem()?.to_str()?.parse().ok()?;
+            Some(BackupEntry {
+                id: backup_id(&hash, timestamp),
+                path: path.clone(),
+                timestamp,
+            })
+        })
+        .collect();
+
+    entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    Ok(entries)
+}
+
+#[tauri::command]
+pub async fn restore_backup(backup_id: String) -> Result<String, String> {
+    let (hash, timestamp) = backup_id
+        .split_once('/')
+        .ok_or_else(|| format!("Invalid backup id: {}", backup_id))?;
+
+    let backup_path = backups_root()?.join(hash).join(format!("{}.json", timestamp));
+
+    fs::read_to_string(&backup_path).map_err(|e| format!("Failed to read backup: {}", e))
+}
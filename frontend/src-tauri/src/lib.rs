@@ -2,83 +2,18 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::Manager;
 
-#[derive(serde::Serialize, serde::Deserialize)]
-struct RecentFile {
-    path: String,
-    name: String,
-    timestamp: u64,
-}
-
-// Custom commands for file operations
-#[tauri::command]
-async fn read_notebook_file(path: String) -> Result<String, String> {
-    fs::read_to_string(&path)
-        .map_err(|e| format!("Failed to read file: {}", e))
-}
-
-#[tauri::command]
-async fn write_notebook_file(path: String, content: String) -> Result<(), String> {
-    fs::write(&path, content)
-        .map_err(|e| format!("Failed to write file: {}", e))
-}
-
-#[tauri::command]
-async fn get_recent_files() -> Result<Vec<RecentFile>, String> {
-    // Load recent files from app data directory
-    let app_dir = tauri::api::path::app_data_dir(&tauri::Config::default())
-        .ok_or("Failed to get app data directory")?;
-
-    let recent_files_path = app_dir.join("recent_files.json");
-
-    if !recent_files_path.exists() {
-        return Ok(vec![]);
-    }
-
-    let content = fs::read_to_string(recent_files_path)
-        .map_err(|e| format!("Failed to read recent files: {}", e))?;
-
-    serde_json::from_str(&content)
-        .map_err(|e| format!("Failed to parse recent files: {}", e))
-}
-
-#[tauri::command]
-async fn add_recent_file(path: String, name: String, timestamp: u64) -> Result<(), String> {
-    let app_dir = tauri::api::path::app_data_dir(&tauri::Config::default())
-        .ok_or("Failed to get app data directory")?;
-
-    if !app_dir.exists() {
-        fs::create_dir_all(&app_dir)
-            .map_err(|e| format!("Failed to create app directory: {}", e))?;
-    }
-
-    let recent_files_path = app_dir.join("recent_files.json");
-
-    let mut recent_files = if recent_files_path.exists() {
-        let content = fs::read_to_string(&recent_files_path)
-            .map_err(|e| format!("Failed to read recent files: {}", e))?;
-        serde_json::from_str::<Vec<RecentFile>>(&content).unwrap_or_default()
-    } else {
-        vec![]
-    };
-
-    // Remove existing entry if present
-    recent_files.retain(|f| f.path != path);
-
-    // Add new entry at the beginning
-    recent_files.insert(0, RecentFile {
-        path,
-        name,
-        timestamp,
-    });
-
-    // Keep only the 10 most recent
-    recent_files.truncate(10);
-
-    let content = serde_json::to_string(&recent_files)
-        .map_err(|e| format!("Failed to serialize recent files: {}", e))?;
-
-    fs::write(recent_files_path, content)
-        .map_err(|e| format!("Failed to write recent files: {}", e))
+mod assets;
+mod backup;
+mod library;
+mod notebook_file;
+mod recent_files;
+mod workspace;
+
+/// Resolves the app data directory, shared by every command that persists
+/// state there (recent files, the library index, backups, ...).
+pub(crate) fn app_data_dir() -> Result<PathBuf, String> {
+    tauri::api::path::app_data_dir(&tauri::Config::default())
+        .ok_or_else(|| "Failed to get app data directory".to_string())
 }
 
 #[tauri::command]
@@ -105,12 +40,24 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
+        .manage(notebook_file::NotebookState::default())
+        .register_uri_scheme_protocol("notebook", assets::handle_notebook_asset)
         .invoke_handler(tauri::generate_handler![
-            read_notebook_file,
-            write_notebook_file,
-            get_recent_files,
-            add_recent_file,
+            notebook_file::read_notebook_file,
+            notebook_file::write_notebook_file,
+            notebook_file::write_notebook_file_checked,
+            recent_files::get_recent_files,
+            recent_files::add_recent_file,
+            recent_files::set_recent_file_pinned,
             get_default_save_directory,
+            library::scan_notebook_library,
+            library::load_cached_library_index,
+            backup::autosave_notebook,
+            backup::list_backups,
+            backup::restore_backup,
+            workspace::open_workspace,
+            workspace::list_workspaces,
+            workspace::get_active_workspace,
         ])
         .setup(|app| {
             if cfg!(debug_assertions) {
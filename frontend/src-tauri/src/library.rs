@@ -0,0 +1,228 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use tauri::Manager;
+use walkdir::{DirEntry, WalkDir};
+
+use crate::app_data_dir;
+
+const IGNORED_DIR_NAMES: [&str; 3] = ["node_modules", "target", ".git"];
+const SCAN_PROGRESS_EVENT: &str = "library-scan-progress";
+
+/// Notebooks are persisted as JSON (see `notebook_file::write_notebook_file`),
+/// so only files with this extension belong in the library tree.
+const NOTEBOOK_EXTENSION: &str = "json";
+
+fn is_notebook_file(entry: &DirEntry) -> bool {
+    entry
+        .path()
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case(NOTEBOOK_EXTENSION))
+        .unwrap_or(false)
+}
+
+#[derive(Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LibraryNode {
+    Directory {
+        name: String,
+        path: String,
+        children: Vec<LibraryNode>,
+    },
+    File {
+        name: String,
+        path: String,
+        size: u64,
+        modified: u64,
+    },
+}
+
+#[derive(Clone, serde::Serialize)]
+struct ScanProgress {
+    scanned: usize,
+    current_path: String,
+}
+
+fn is_hidden_or_ignored(entry: &DirEntry) -> bool {
+    entry
+        .file_name()
+        .to_str()
+        .map(|name| name.starts_with('.') || IGNORED_DIR_NAMES.contains(&name))
+        .unwrap_or(false)
+}
+
+struct ScannedFile {
+    relative_path: PathBuf,
+    size: u64,
+    modified: u64,
+}
+
+fn walk(root: &Path, app: &tauri::AppHandle) -> Result<Vec<ScannedFile>, String> {
+    let mut files = Vec::new();
+    let mut scanned = 0usize;
+
+    let walker = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !is_hidden_or_ignored(e));
+
+    for entry in walker {
+        let entry = entry.map_err(|e| format!("Failed to walk library: {}", e))?;
+        if !entry.file_type().is_file() || !is_notebook_file(&entry) {
+            continue;
+        }
+
+        let metadata = entry
+            .metadata()
+            .map_err(|e| format!("Failed to read metadata for {}: {}", entry.path().display(), e))?;
+
+        let modified = metadata
+            .modified()
+            .map_err(|e| format!("Failed to read modified time: {}", e))?
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("Invalid modified time: {}", e))?
+            .as_secs();
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(root)
+            .map_err(|e| format!("Failed to resolve relative path: {}", e))?
+            .to_path_buf();
+
+        scanned += 1;
+        let _ = app.emit_all(
+            SCAN_PROGRESS_EVENT,
+            ScanProgress {
+                scanned,
+                current_path: relative_path.to_string_lossy().to_string(),
+            },
+        );
+
+        files.push(ScannedFile {
+            relative_path,
+            size: metadata.len(),
+            modified,
+        });
+    }
+
+    Ok(files)
+}
+
+fn build_tree(root_name: String, files: Vec<ScannedFile>) -> LibraryNode {
+    enum Node {
+        Dir(BTreeMap<String, Node>),
+        File { size: u64, modified: u64 },
+    }
+
+    let mut root: BTreeMap<String, Node> = BTreeMap::new();
+
+    'files: for file in files {
+        let mut components: Vec<String> = file
+            .relative_path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+        let Some(file_name) = components.pop() else {
+            continue;
+        };
+
+        let mut cursor = &mut root;
+        for component in components {
+            let entry = cursor
+                .entry(component)
+                .or_insert_with(|| Node::Dir(BTreeMap::new()));
+            match entry {
+                Node::Dir(children) => cursor = children,
+                // A real filesystem walk can't produce this (a path can't be
+                // both a file and a directory). Skip the colliding entry
+                // rather than panicking.
+                Node::File { .. } => continue 'files,
+            }
+        }
+
+        cursor.insert(
+            file_name,
+            Node::File {
+                size: file.size,
+                modified: file.modified,
+            },
+        );
+    }
+
+    fn into_library_node(name: String, path: PathBuf, node: Node) -> LibraryNode {
+        match node {
+            Node::File { size, modified } => LibraryNode::File {
+                name,
+                path: path.to_string_lossy().to_string(),
+                size,
+                modified,
+            },
+            Node::Dir(children) => LibraryNode::Directory {
+                name,
+                path: path.to_string_lossy().to_string(),
+                children: children
+                    .into_iter()
+                    .map(|(child_name, child_node)| {
+                        let child_path = path.join(&child_name);
+                        into_library_node(child_name, child_path, child_node)
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    into_library_node(root_name, PathBuf::new(), Node::Dir(root))
+}
+
+const LIBRARY_INDEX_FILE_NAME: &str = "library_index.json";
+
+/// Returns the last index written by `scan_notebook_library`, if any, so a
+/// cold UI can render a sidebar immediately instead of waiting on a full
+/// rescan.
+#[tauri::command]
+pub async fn load_cached_library_index() -> Result<Option<LibraryNode>, String> {
+    let index_path = app_data_dir()?.join(LIBRARY_INDEX_FILE_NAME);
+    if !index_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(index_path)
+        .map_err(|e| format!("Failed to read library index: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse library index: {}", e))
+}
+
+#[tauri::command]
+pub async fn scan_notebook_library(
+    app: tauri::AppHandle,
+    root: String,
+) -> Result<LibraryNode, String> {
+    let root_path = PathBuf::from(&root);
+    if !root_path.is_dir() {
+        return Err(format!("{} is not a directory", root));
+    }
+
+    let files = walk(&root_path, &app)?;
+
+    let root_name = root_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| root.clone());
+    let tree = build_tree(root_name, files);
+
+    let app_dir = app_data_dir()?;
+    if !app_dir.exists() {
+        fs::create_dir_all(&app_dir)
+            .map_err(|e| format!("Failed to create app directory: {}", e))?;
+    }
+
+    let serialized = serde_json::to_string(&tree)
+        .map_err(|e| format!("Failed to serialize library index: {}", e))?;
+    fs::write(app_dir.join(LIBRARY_INDEX_FILE_NAME), serialized)
+        .map_err(|e| format!("Failed to write library index: {}", e))?;
+
+    Ok(tree)
+}
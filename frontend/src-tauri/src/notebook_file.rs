@@ -0,0 +1,95 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Tracks the directory of the most recently opened notebook, so the
+/// `notebook://` asset protocol knows what to resolve relative paths
+/// against.
+#[derive(Default)]
+pub struct NotebookState(pub Mutex<Option<PathBuf>>);
+
+/// Error returned by the checked write path, distinguishing an ordinary I/O
+/// failure from a detected external modification.
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "kind", content = "message")]
+pub enum WriteError {
+    Io(String),
+    Conflict(String),
+}
+
+impl From<std::io::Error> for WriteError {
+    fn from(e: std::io::Error) -> Self {
+        WriteError::Io(e.to_string())
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct NotebookFile {
+    content: String,
+    hash: String,
+}
+
+fn hash_bytes(bytes: &[u8]) -> String {
+    blake3::hash(bytes).to_hex().to_string()
+}
+
+/// Writes `content` to a temp file in the same directory as `path`, then
+/// renames it into place, so a crash mid-write can't leave a corrupt
+/// notebook behind.
+fn atomic_write(path: &str, content: &str) -> std::io::Result<()> {
+    let path = Path::new(path);
+    let dir = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or_else(|| Path::new("."));
+    let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    let tmp_path = dir.join(format!(".{}.tmp", file_name));
+
+    fs::write(&tmp_path, content)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn read_notebook_file(
+    path: String,
+    state: tauri::State<'_, NotebookState>,
+) -> Result<NotebookFile, String> {
+    let bytes = fs::read(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let hash = hash_bytes(&bytes);
+    let content =
+        String::from_utf8(bytes).map_err(|e| format!("File is not valid UTF-8: {}", e))?;
+
+    if let Some(dir) = Path::new(&path).parent() {
+        *state.0.lock().unwrap() = Some(dir.to_path_buf());
+    }
+
+    Ok(NotebookFile { content, hash })
+}
+
+#[tauri::command]
+pub async fn write_notebook_file(path: String, content: String) -> Result<(), String> {
+    atomic_write(&path, &content).map_err(|e| format!("Failed to write file: {}", e))
+}
+
+/// Writes `content` to `path`, but only if the file on disk still matches
+/// `expected_hash`. Used to detect another process (or editor window) having
+/// changed the file since the caller last read it. Returns the new content's
+/// hash so the caller can update its known digest without a re-read.
+#[tauri::command]
+pub async fn write_notebook_file_checked(
+    path: String,
+    content: String,
+    expected_hash: String,
+) -> Result<String, WriteError> {
+    if Path::new(&path).exists() {
+        let on_disk = fs::read(&path)?;
+        let actual_hash = hash_bytes(&on_disk);
+        if actual_hash != expected_hash {
+            return Err(WriteError::Conflict(format!(
+                "{} was modified on disk since it was last read",
+                path
+            )));
+        }
+    }
+
+    atomic_write(&path, &content)?;
+    Ok(hash_bytes(content.as_bytes()))
+}
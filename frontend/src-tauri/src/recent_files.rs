@@ -0,0 +1,177 @@
+use std::cmp::Ordering;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::app_data_dir;
+
+const MAX_RECENT_FILES: usize = 10;
+const RECENT_FILES_FILE_NAME: &str = "recent_files.json";
+
+/// How fast an entry's recency contribution decays, in days. An entry
+/// opened a week ago contributes half of what it did the day it was
+/// opened.
+const RECENCY_HALF_LIFE_DAYS: f64 = 7.0;
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct RecentFileEntry {
+    path: String,
+    name: String,
+    modified: u64,
+    access_count: u32,
+    last_accessed: u64,
+    #[serde(default)]
+    pinned: bool,
+}
+
+#[derive(serde::Serialize)]
+pub struct RecentFile {
+    path: String,
+    name: String,
+    modified: u64,
+    score: f64,
+    pinned: bool,
+}
+
+fn now() -> Result<u64, String> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(|e| format!("Invalid system time: {}", e))
+}
+
+fn modified_time_of(path: &str, fallback: u64) -> u64 {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(fallback)
+}
+
+/// Frecency score: access count weighted by how recently the entry was
+/// last opened, so a notebook opened often but a while ago can still beat
+/// one opened once yesterday, without ever surpassing one opened often and
+/// recently.
+fn frecency_score(entry: &RecentFileEntry, now: u64) -> f64 {
+    let age_days = now.saturating_sub(entry.last_accessed) as f64 / 86_400.0;
+    let recency = 0.5_f64.powf(age_days / RECENCY_HALF_LIFE_DAYS);
+    entry.access_count as f64 * recency
+}
+
+fn cmp_for_ranking(a: &RecentFileEntry, b: &RecentFileEntry, now: u64) -> Ordering {
+    b.pinned
+        .cmp(&a.pinned)
+        .then_with(|| frecency_score(b, now).partial_cmp(&frecency_score(a, now)).unwrap_or(Ordering::Equal))
+}
+
+fn recent_files_path() -> Result<PathBuf, String> {
+    Ok(app_data_dir()?.join(RECENT_FILES_FILE_NAME))
+}
+
+fn read_entries() -> Result<Vec<RecentFileEntry>, String> {
+    let path = recent_files_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read recent files: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse recent files: {}", e))
+}
+
+fn write_entries(entries: &[RecentFileEntry]) -> Result<(), String> {
+    let app_dir = app_data_dir()?;
+    if !app_dir.exists() {
+        fs::create_dir_all(&app_dir)
+            .map_err(|e| format!("Failed to create app directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string(entries)
+        .map_err(|e| format!("Failed to serialize recent files: {}", e))?;
+    fs::write(recent_files_path()?, content)
+        .map_err(|e| format!("Failed to write recent files: {}", e))
+}
+
+#[tauri::command]
+pub async fn get_recent_files() -> Result<Vec<RecentFile>, String> {
+    let mut entries = read_entries()?;
+
+    // Drop entries whose file no longer exists, and refresh `modified` from
+    // disk rather than trusting whatever was last recorded.
+    entries.retain_mut(|entry| match fs::metadata(&entry.path) {
+        Ok(metadata) => {
+            if let Some(modified) = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            {
+                entry.modified = modified.as_secs();
+            }
+            true
+        }
+        Err(_) => false,
+    });
+
+    write_entries(&entries)?;
+
+    let now = now()?;
+    let mut files: Vec<RecentFile> = entries
+        .iter()
+        .map(|entry| RecentFile {
+            path: entry.path.clone(),
+            name: entry.name.clone(),
+            modified: entry.modified,
+            score: frecency_score(entry, now),
+            pinned: entry.pinned,
+        })
+        .collect();
+
+    files.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            .then(b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal))
+    });
+
+    Ok(files)
+}
+
+#[tauri::command]
+pub async fn add_recent_file(path: String, name: String) -> Result<(), String> {
+    let mut entries = read_entries()?;
+    let now = now()?;
+    let modified = modified_time_of(&path, now);
+
+    match entries.iter_mut().find(|e| e.path == path) {
+        Some(existing) => {
+            existing.access_count += 1;
+            existing.last_accessed = now;
+            existing.modified = modified;
+            existing.name = name;
+        }
+        None => entries.push(RecentFileEntry {
+            path,
+            name,
+            modified,
+            access_count: 1,
+            last_accessed: now,
+            pinned: false,
+        }),
+    }
+
+    if entries.len() > MAX_RECENT_FILES {
+        entries.sort_by(|a, b| cmp_for_ranking(a, b, now));
+        entries.truncate(MAX_RECENT_FILES);
+    }
+
+    write_entries(&entries)
+}
+
+#[tauri::command]
+pub async fn set_recent_file_pinned(path: String, pinned: bool) -> Result<(), String> {
+    let mut entries = read_entries()?;
+    if let Some(entry) = entries.iter_mut().find(|e| e.path == path) {
+        entry.pinned = pinned;
+    }
+    write_entries(&entries)
+}
@@ -0,0 +1,169 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::app_data_dir;
+
+const MANIFEST_FILE_NAME: &str = "workspace.toml";
+const REGISTRY_FILE_NAME: &str = "workspaces.json";
+const ACTIVE_WORKSPACE_FILE_NAME: &str = "active_workspace.json";
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ActiveWorkspace {
+    root: String,
+}
+
+/// The `workspace.toml` manifest stored at a workspace's root.
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+struct WorkspaceManifest {
+    name: String,
+    #[serde(default)]
+    default_path: Option<String>,
+    #[serde(default)]
+    last_opened: u64,
+}
+
+#[derive(serde::Serialize, Clone)]
+pub struct WorkspaceSummary {
+    root: String,
+    name: String,
+    default_path: Option<String>,
+    last_opened: u64,
+}
+
+fn now() -> Result<u64, String> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(|e| format!("Invalid system time: {}", e))
+}
+
+fn registry_path() -> Result<PathBuf, String> {
+    Ok(app_data_dir()?.join(REGISTRY_FILE_NAME))
+}
+
+fn read_registry() -> Result<Vec<String>, String> {
+    let path = registry_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read workspace registry: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse workspace registry: {}", e))
+}
+
+fn write_registry(roots: &[String]) -> Result<(), String> {
+    let app_dir = app_data_dir()?;
+    if !app_dir.exists() {
+        fs::create_dir_all(&app_dir)
+            .map_err(|e| format!("Failed to create app directory: {}", e))?;
+    }
+
+    let content = serde_json::to_string(roots)
+        .map_err(|e| format!("Failed to serialize workspace registry: {}", e))?;
+    fs::write(registry_path()?, content)
+        .map_err(|e| format!("Failed to write workspace registry: {}", e))
+}
+
+fn manifest_path(root: &str) -> PathBuf {
+    Path::new(root).join(MANIFEST_FILE_NAME)
+}
+
+fn read_manifest(root: &str) -> Result<WorkspaceManifest, String> {
+    let path = manifest_path(root);
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    toml::from_str(&content).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+fn write_manifest(root: &str, manifest: &WorkspaceManifest) -> Result<(), String> {
+    let path = manifest_path(root);
+    let content = toml::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize workspace manifest: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn to_summary(root: String, manifest: WorkspaceManifest) -> WorkspaceSummary {
+    WorkspaceSummary {
+        root,
+        name: manifest.name,
+        default_path: manifest.default_path,
+        last_opened: manifest.last_opened,
+    }
+}
+
+/// Opens (or initializes) the workspace rooted at `path`, stamps it as the
+/// active workspace, and registers it so it shows up in `list_workspaces`.
+#[tauri::command]
+pub async fn open_workspace(path: String) -> Result<WorkspaceSummary, String> {
+    let root_path = Path::new(&path);
+    if !root_path.is_dir() {
+        return Err(format!("{} is not a directory", path));
+    }
+
+    let mut manifest = read_manifest(&path).unwrap_or_else(|_| WorkspaceManifest {
+        name: root_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone()),
+        default_path: None,
+        last_opened: 0,
+    });
+    manifest.last_opened = now()?;
+    write_manifest(&path, &manifest)?;
+
+    let mut roots = read_registry()?;
+    if !roots.iter().any(|r| r == &path) {
+        roots.push(path.clone());
+        write_registry(&roots)?;
+    }
+
+    let app_dir = app_data_dir()?;
+    let active = ActiveWorkspace { root: path.clone() };
+    let content = serde_json::to_string(&active)
+        .map_err(|e| format!("Failed to serialize active workspace: {}", e))?;
+    fs::write(app_dir.join(ACTIVE_WORKSPACE_FILE_NAME), content)
+        .map_err(|e| format!("Failed to record active workspace: {}", e))?;
+
+    Ok(to_summary(path, manifest))
+}
+
+#[tauri::command]
+pub async fn list_workspaces() -> Result<Vec<WorkspaceSummary>, String> {
+    let roots = read_registry()?;
+    let mut summaries: Vec<WorkspaceSummary> = roots
+        .into_iter()
+        .filter_map(|root| read_manifest(&root).ok().map(|manifest| to_summary(root, manifest)))
+        .collect();
+
+    summaries.sort_by(|a, b| b.last_opened.cmp(&a.last_opened));
+    Ok(summaries)
+}
+
+/// Like [`get_active_workspace`], but infallible for callers (such as the
+/// asset protocol handler) that just want a best-effort root to scope paths
+/// to and would rather fall back than fail.
+pub(crate) fn active_workspace_root() -> Option<PathBuf> {
+    let active_path = app_data_dir().ok()?.join(ACTIVE_WORKSPACE_FILE_NAME);
+    let content = fs::read_to_string(active_path).ok()?;
+    let active: ActiveWorkspace = serde_json::from_str(&content).ok()?;
+    Some(PathBuf::from(active.root))
+}
+
+#[tauri::command]
+pub async fn get_active_workspace() -> Result<Option<WorkspaceSummary>, String> {
+    let active_path = app_data_dir()?.join(ACTIVE_WORKSPACE_FILE_NAME);
+    if !active_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&active_path)
+        .map_err(|e| format!("Failed to read active workspace pointer: {}", e))?;
+    let active: ActiveWorkspace = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse active workspace pointer: {}", e))?;
+    let manifest = read_manifest(&active.root)?;
+
+    Ok(Some(to_summary(active.root, manifest)))
+}